@@ -0,0 +1,593 @@
+use color_eyre::Result;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Sparkline},
+    DefaultTerminal, Frame,
+};
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+
+use crate::cache::{CacheApi, ModePlan};
+use crate::chart::{sparkline_data, TimePeriod};
+use crate::price_source::{BinancePriceApi, CoinPriceApiT, MockPriceApi};
+use crate::theme::{Resources, Theme};
+
+/// How far along a given coin's price fetch is.
+#[derive(Debug, Clone, Copy)]
+enum PriceState {
+    /// No successful fetch has landed yet.
+    Loading,
+    Loaded(f64),
+    /// The most recent fetch failed; keep showing this until the next tick succeeds.
+    Error,
+}
+
+// Configuration for each coin
+#[derive(Debug, Clone)]
+struct CoinConfig {
+    symbol: String,
+    display_name: String,
+    /// Index into the active theme's accent palette; resolved via `Theme::accent`.
+    accent: usize,
+    precision: usize,
+    /// Holding size, if this coin is part of the portfolio view.
+    quantity: Option<f64>,
+}
+
+impl Default for CoinConfig {
+    fn default() -> Self {
+        Self {
+            symbol: String::new(),
+            display_name: String::new(),
+            accent: 0,
+            precision: 2,
+            quantity: None,
+        }
+    }
+}
+
+/// Which screen `render` draws: the flat live-price list, or the portfolio summary.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ViewMode {
+    Live,
+    Portfolio,
+}
+
+/// The main application which holds the state and logic of the application.
+#[derive(Debug)]
+pub struct App {
+    /// Is the application running?
+    running: bool,
+    prices: Vec<PriceState>,
+    coin_configs: Vec<CoinConfig>,
+    /// Rolling close-price history per coin, bounded to the active period's look-back window.
+    histories: Vec<VecDeque<f64>>,
+    /// 24h percent change per coin, used to color portfolio rows.
+    changes: Vec<f64>,
+    period: TimePeriod,
+    view: ViewMode,
+    market_rx: mpsc::Receiver<MarketUpdate>,
+    period_tx: watch::Sender<TimePeriod>,
+    resources: Resources,
+    // Keeps the background fetch task alive for as long as the app runs.
+    _runtime: tokio::runtime::Runtime,
+}
+
+/// One tick's worth of data from the background fetch task.
+struct MarketUpdate {
+    prices: Vec<PriceState>,
+    histories: Vec<Vec<f64>>,
+    changes: Vec<f64>,
+}
+
+impl App {
+    /// Construct a new instance of [`App`].
+    pub fn new() -> Self {
+        // Define your coin configurations here
+        let coin_configs = vec![
+            CoinConfig {
+                symbol: "BTCUSDT".to_string(),
+                display_name: "BTC/USDT".to_string(),
+                accent: 0,
+                precision: 2,
+                quantity: Some(0.05),
+            },
+            CoinConfig {
+                symbol: "ETHUSDT".to_string(),
+                display_name: "ETH/USDT".to_string(),
+                accent: 1,
+                precision: 2,
+                quantity: Some(1.2),
+            },
+            CoinConfig {
+                symbol: "BNBUSDT".to_string(),
+                display_name: "BNB/USDT".to_string(),
+                accent: 2,
+                precision: 2,
+                quantity: Some(3.0),
+            },
+            CoinConfig {
+                symbol: "UNIUSDT".to_string(),
+                display_name: "UNI/USDT".to_string(),
+                accent: 3,
+                precision: 2,
+                quantity: None,
+            },
+            CoinConfig {
+                symbol: "TONUSDT".to_string(),
+                display_name: "TON/USDT".to_string(),
+                accent: 3,
+                precision: 2,
+                quantity: None,
+            },
+            CoinConfig {
+                symbol: "SOLUSDT".to_string(),
+                display_name: "SOL/USDT".to_string(),
+                accent: 3,
+                precision: 2,
+                quantity: Some(10.0),
+            },
+            CoinConfig {
+                symbol: "XRPUSDT".to_string(),
+                display_name: "XRP/USDT".to_string(),
+                accent: 4,
+                precision: 4,
+                quantity: Some(500.0),
+            },
+            CoinConfig {
+                symbol: "DOGEUSDT".to_string(),
+                display_name: "DOGE/USDT".to_string(),
+                accent: 5,
+                precision: 6,
+                quantity: Some(2500.0),
+            },
+            CoinConfig {
+                symbol: "TONUSDT".to_string(),
+                display_name: "TON/USDT".to_string(),
+                accent: 1,
+                precision: 4,
+                quantity: None,
+            },
+            CoinConfig {
+                symbol: "ADAUSDT".to_string(),
+                display_name: "ADA/USDT".to_string(),
+                accent: 4,
+                precision: 4,
+                quantity: None,
+            },
+        ];
+
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_time()
+            .build()
+            .expect("failed to start background runtime");
+
+        let (market_tx, market_rx) = mpsc::channel(8);
+        let (period_tx, period_rx) = watch::channel(TimePeriod::Day);
+        let symbols: Vec<String> = coin_configs.iter().map(|c| c.symbol.clone()).collect();
+        let api = Self::select_price_api(&symbols);
+        runtime.spawn(fetch_market_data_loop(api, symbols, period_rx, market_tx));
+
+        let num_coins = coin_configs.len();
+        Self {
+            running: false,
+            prices: vec![PriceState::Loading; num_coins],
+            coin_configs,
+            histories: vec![VecDeque::new(); num_coins],
+            changes: vec![0.0; num_coins],
+            period: TimePeriod::Day,
+            view: ViewMode::Live,
+            market_rx,
+            period_tx,
+            resources: Resources::new(),
+            _runtime: runtime,
+        }
+    }
+
+    /// Run the application's main loop.
+    pub fn run(mut self, mut terminal: DefaultTerminal) -> Result<()> {
+        self.running = true;
+        while self.running {
+            terminal.draw(|frame| self.render(frame))?;
+
+            self.drain_market_updates();
+
+            // Poll for events with a timeout to prevent CPU spinning
+            if event::poll(Duration::from_millis(250))? {
+                self.handle_crossterm_events()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Pulls any price/history updates the background task has pushed since the last frame.
+    fn drain_market_updates(&mut self) {
+        while let Ok(update) = self.market_rx.try_recv() {
+            self.prices = update.prices;
+            self.changes = update.changes;
+            for (history, closes) in self.histories.iter_mut().zip(update.histories) {
+                *history = closes.into();
+            }
+        }
+    }
+
+    /// Renders the user interface.
+    ///
+    /// This is where you add new widgets. See the following resources for more information:
+    ///
+    /// - <https://docs.rs/ratatui/latest/ratatui/widgets/index.html>
+    /// - <https://github.com/ratatui/ratatui/tree/main/ratatui-widgets/examples>
+    fn render(&mut self, frame: &mut Frame) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3),                            // Title
+                Constraint::Length((self.coin_configs.len() as u16) + 2), // Prices + sparklines
+            ])
+            .split(frame.area());
+
+        let theme = self.resources.theme;
+
+        // Title
+        let title = Line::from(vec![Span::styled(
+            "Crypto Price Tracker",
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .fg(theme.title_color),
+        )])
+        .centered();
+        frame.render_widget(
+            Paragraph::new(title)
+                .block(Block::default().borders(Borders::ALL).border_style(theme.border_style))
+                .style(Style::default().bg(theme.background)),
+            chunks[0],
+        );
+
+        match self.view {
+            ViewMode::Live => self.render_live(frame, chunks[1], theme),
+            ViewMode::Portfolio => self.render_portfolio(frame, chunks[1], theme),
+        }
+    }
+
+    /// Renders the flat list of live prices with a sparkline next to each coin.
+    fn render_live(&self, frame: &mut Frame, area: Rect, theme: Theme) {
+        let prices_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(theme.border_style)
+            .style(Style::default().bg(theme.background))
+            .title(format!("Live Prices  [{}]", self.period.label()));
+        let inner_area = prices_block.inner(area);
+        frame.render_widget(prices_block, area);
+
+        let row_constraints: Vec<Constraint> = self
+            .coin_configs
+            .iter()
+            .map(|_| Constraint::Length(1))
+            .collect();
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(row_constraints)
+            .split(inner_area);
+
+        for (i, config) in self.coin_configs.iter().enumerate() {
+            let cols = Layout::default()
+                .direction(Direction::Horizontal)
+                .constraints([Constraint::Length(24), Constraint::Min(10)])
+                .split(rows[i]);
+
+            let price_span = match self.prices[i] {
+                PriceState::Loading => {
+                    Span::styled("loading...", Style::default().fg(Color::DarkGray))
+                }
+                PriceState::Loaded(price) => Span::styled(
+                    format!("${:.prec$}", price, prec = config.precision),
+                    Style::default().fg(theme.accent(config.accent)),
+                ),
+                PriceState::Error => Span::styled("error", Style::default().fg(Color::Red)),
+            };
+            let label_line =
+                Line::from(vec![Span::raw(format!("{}:  ", config.display_name)), price_span]);
+            frame.render_widget(Paragraph::new(label_line), cols[0]);
+
+            let closes: Vec<f64> = self.histories[i].iter().copied().collect();
+            let sparkline = Sparkline::default()
+                .data(&sparkline_data(&closes))
+                .style(Style::default().fg(theme.accent(config.accent)));
+            frame.render_widget(sparkline, cols[1]);
+        }
+    }
+
+    /// Renders holdings x current price per coin, 24h change coloring, sorted by
+    /// descending value, with the aggregate portfolio value in the footer.
+    fn render_portfolio(&self, frame: &mut Frame, area: Rect, theme: Theme) {
+        let (rows, total) = portfolio_rows(&self.coin_configs, &self.prices, &self.changes);
+
+        let mut lines: Vec<Line> = rows
+            .iter()
+            .map(|row| {
+                let (arrow, change_color) = if row.change >= 0.0 {
+                    ("▲", Color::Green)
+                } else {
+                    ("▼", Color::Red)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<10}", row.config.display_name),
+                        Style::default().fg(theme.accent(row.config.accent)),
+                    ),
+                    Span::raw(format!("${:>12.2}  ", row.value)),
+                    Span::styled(
+                        format!("{arrow} {:>6.2}%", row.change),
+                        Style::default().fg(change_color),
+                    ),
+                ])
+            })
+            .collect();
+        lines.push(Line::from(vec![Span::styled(
+            format!("Total: ${total:.2}"),
+            Style::default().add_modifier(Modifier::BOLD),
+        )]));
+
+        frame.render_widget(
+            Paragraph::new(lines)
+                .block(
+                    Block::default()
+                        .borders(Borders::ALL)
+                        .border_style(theme.border_style)
+                        .title("Portfolio"),
+                )
+                .style(Style::default().bg(theme.background)),
+            area,
+        );
+    }
+
+    /// Reads the crossterm events and updates the state of [`App`].
+    ///
+    /// If your application needs to perform work in between handling events, you can use the
+    /// [`event::poll`] function to check if there are any events available with a timeout.
+    fn handle_crossterm_events(&mut self) -> Result<()> {
+        match event::read()? {
+            // it's important to check KeyEventKind::Press to avoid handling key release events
+            Event::Key(key) if key.kind == KeyEventKind::Press => self.on_key_event(key),
+            Event::Mouse(_) => {}
+            Event::Resize(_, _) => {}
+            _ => {}
+        }
+        Ok(())
+    }
+
+    /// Handles the key events and updates the state of [`App`].
+    fn on_key_event(&mut self, key: KeyEvent) {
+        match (key.modifiers, key.code) {
+            (_, KeyCode::Esc | KeyCode::Char('q'))
+            | (KeyModifiers::CONTROL, KeyCode::Char('c') | KeyCode::Char('C')) => self.quit(),
+            (_, KeyCode::Char('t')) => self.resources.cycle_theme(),
+            (_, KeyCode::Left) => self.set_period(self.period.prev()),
+            (_, KeyCode::Right) => self.set_period(self.period.next()),
+            (_, KeyCode::Char('p')) => self.toggle_view(),
+            // Add other key handlers here.
+            _ => {}
+        }
+    }
+
+    /// Toggles between the live-price list and the portfolio summary.
+    fn toggle_view(&mut self) {
+        self.view = match self.view {
+            ViewMode::Live => ViewMode::Portfolio,
+            ViewMode::Portfolio => ViewMode::Live,
+        };
+    }
+
+    /// Switches the sparkline look-back window and tells the background task to
+    /// start fetching candles for the new period.
+    fn set_period(&mut self, period: TimePeriod) {
+        self.period = period;
+        let _ = self.period_tx.send(period);
+    }
+
+    /// Set running to false to quit the application.
+    fn quit(&mut self) {
+        self.running = false;
+    }
+
+    /// Picks the live Binance API unless `--mock` or `MOCK_PRICES` asks for the
+    /// deterministic mock, then wraps it in a [`CacheApi`] so most symbols are
+    /// actually throttled instead of all polled every second — ten-plus symbols
+    /// hitting Binance once a second each is exactly the rate-limit risk this
+    /// decorator exists to avoid.
+    fn select_price_api(symbols: &[String]) -> Box<dyn CoinPriceApiT> {
+        let use_mock =
+            std::env::args().any(|arg| arg == "--mock") || std::env::var("MOCK_PRICES").is_ok();
+        let inner: Box<dyn CoinPriceApiT> = if use_mock {
+            Box::new(MockPriceApi::new())
+        } else {
+            Box::new(BinancePriceApi::new())
+        };
+
+        // Stagger refresh rates across symbols: a third stay fully live, a third
+        // refresh at most every 5s, and a third every 10s.
+        let modes = symbols
+            .iter()
+            .enumerate()
+            .map(|(i, symbol)| {
+                let mode = match i % 3 {
+                    0 => ModePlan::Transparent,
+                    1 => ModePlan::Slow(Duration::from_secs(5)),
+                    _ => ModePlan::Slow(Duration::from_secs(10)),
+                };
+                (symbol.clone(), mode)
+            })
+            .collect();
+
+        Box::new(CacheApi::new(inner, modes))
+    }
+}
+
+/// One row of the portfolio view: a holding's current value and 24h change.
+struct PortfolioRow<'a> {
+    config: &'a CoinConfig,
+    value: f64,
+    change: f64,
+}
+
+/// Builds the portfolio rows and their total value. A coin is included only if it
+/// has a configured `quantity` and its price has loaded; everything else (no
+/// holding, still loading, or errored) is left out of both the rows and the total.
+/// Rows come back sorted by descending value.
+fn portfolio_rows<'a>(
+    coin_configs: &'a [CoinConfig],
+    prices: &[PriceState],
+    changes: &[f64],
+) -> (Vec<PortfolioRow<'a>>, f64) {
+    let mut rows: Vec<PortfolioRow> = coin_configs
+        .iter()
+        .enumerate()
+        .filter_map(|(i, config)| {
+            let quantity = config.quantity?;
+            let price = match prices[i] {
+                PriceState::Loaded(price) => price,
+                _ => return None,
+            };
+            Some(PortfolioRow {
+                config,
+                value: quantity * price,
+                change: changes[i],
+            })
+        })
+        .collect();
+    rows.sort_by(|a, b| b.value.total_cmp(&a.value));
+
+    let total: f64 = rows.iter().map(|row| row.value).sum();
+    (rows, total)
+}
+
+/// How often kline history and 24h stats are refreshed, in price-poll ticks. They
+/// don't need second-level resolution the way the live price does, and fetching
+/// them every tick for every symbol is most of this app's Binance request volume.
+const SLOW_DATA_EVERY_TICKS: u32 = 5;
+
+/// Background task that fetches every symbol's price once a second (subject to each
+/// symbol's [`ModePlan`]) and pushes the full snapshot back to the UI thread, so the
+/// render loop never blocks on the network. Reads the look-back window from
+/// `period_rx` each time it refreshes history, so a period change takes effect on
+/// the next slow-data tick rather than immediately.
+async fn fetch_market_data_loop(
+    api: Box<dyn CoinPriceApiT>,
+    symbols: Vec<String>,
+    period_rx: watch::Receiver<TimePeriod>,
+    tx: mpsc::Sender<MarketUpdate>,
+) {
+    let mut interval = tokio::time::interval(Duration::from_secs(1));
+    let mut histories = vec![Vec::new(); symbols.len()];
+    let mut changes = vec![0.0; symbols.len()];
+    let mut tick: u32 = 0;
+
+    loop {
+        interval.tick().await;
+
+        // One Result per symbol: a single failing symbol lands as PriceState::Error
+        // for that slot only, the rest of the batch still updates.
+        let prices = api
+            .get_prices(&symbols)
+            .into_iter()
+            .map(|result| match result {
+                Ok(price) => PriceState::Loaded(price),
+                Err(_) => PriceState::Error,
+            })
+            .collect();
+
+        if tick % SLOW_DATA_EVERY_TICKS == 0 {
+            let period = *period_rx.borrow();
+            histories = symbols
+                .iter()
+                .map(|symbol| {
+                    api.get_klines(symbol, period.kline_interval(), period.lookback())
+                        .unwrap_or_default()
+                })
+                .collect();
+            changes = api
+                .get_24h_changes(&symbols)
+                .into_iter()
+                .map(|result| result.unwrap_or(0.0))
+                .collect();
+        }
+        tick = tick.wrapping_add(1);
+
+        if tx
+            .send(MarketUpdate {
+                prices,
+                histories: histories.clone(),
+                changes: changes.clone(),
+            })
+            .await
+            .is_err()
+        {
+            // UI side has dropped the receiver; nothing left to do.
+            return;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn coin(symbol: &str, quantity: Option<f64>) -> CoinConfig {
+        CoinConfig {
+            symbol: symbol.to_string(),
+            display_name: symbol.to_string(),
+            quantity,
+            ..CoinConfig::default()
+        }
+    }
+
+    #[test]
+    fn excludes_coins_without_a_quantity() {
+        let configs = vec![coin("BTCUSDT", Some(1.0)), coin("UNIUSDT", None)];
+        let prices = vec![PriceState::Loaded(100.0), PriceState::Loaded(5.0)];
+        let changes = vec![0.0, 0.0];
+
+        let (rows, _) = portfolio_rows(&configs, &prices, &changes);
+
+        assert_eq!(rows.len(), 1);
+        assert_eq!(rows[0].config.symbol, "BTCUSDT");
+    }
+
+    #[test]
+    fn excludes_coins_whose_price_has_not_loaded() {
+        let configs = vec![coin("BTCUSDT", Some(1.0)), coin("ETHUSDT", Some(2.0))];
+        let prices = vec![PriceState::Loading, PriceState::Error];
+        let changes = vec![0.0, 0.0];
+
+        let (rows, total) = portfolio_rows(&configs, &prices, &changes);
+
+        assert!(rows.is_empty());
+        assert_eq!(total, 0.0);
+    }
+
+    #[test]
+    fn sorts_rows_by_descending_value() {
+        let configs = vec![
+            coin("BTCUSDT", Some(1.0)),
+            coin("ETHUSDT", Some(10.0)),
+            coin("BNBUSDT", Some(2.0)),
+        ];
+        let prices = vec![
+            PriceState::Loaded(100.0), // value 100
+            PriceState::Loaded(50.0),  // value 500
+            PriceState::Loaded(10.0),  // value 20
+        ];
+        let changes = vec![0.0, 0.0, 0.0];
+
+        let (rows, total) = portfolio_rows(&configs, &prices, &changes);
+
+        let values: Vec<f64> = rows.iter().map(|row| row.value).collect();
+        assert_eq!(values, vec![500.0, 100.0, 20.0]);
+        assert_eq!(total, 620.0);
+    }
+}
@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use color_eyre::Result;
+
+use crate::price_source::CoinPriceApiT;
+
+/// How aggressively a given symbol should be refreshed.
+#[derive(Debug, Clone, Copy)]
+pub enum ModePlan {
+    /// Always forward the call to the wrapped source.
+    Transparent,
+    /// Refresh at most once per `Duration`, serving the cached value in between.
+    Slow(Duration),
+    /// Serve the cached value forever after the first successful fetch.
+    Cached,
+}
+
+/// Decorates a [`CoinPriceApiT`] with a per-symbol refresh policy, so callers can
+/// stagger request rates across many symbols without the network layer or the UI
+/// needing to know about it.
+pub struct CacheApi {
+    inner: Box<dyn CoinPriceApiT>,
+    modes: HashMap<String, ModePlan>,
+    cache: Mutex<HashMap<String, (f64, Instant)>>,
+}
+
+impl CacheApi {
+    pub fn new(inner: Box<dyn CoinPriceApiT>, modes: HashMap<String, ModePlan>) -> Self {
+        Self {
+            inner,
+            modes,
+            cache: Mutex::new(HashMap::new()),
+        }
+    }
+}
+
+impl CoinPriceApiT for CacheApi {
+    fn get_prices(&self, symbols: &[String]) -> Vec<Result<f64>> {
+        let mut cache = self.cache.lock().unwrap();
+        let mut results: Vec<Option<Result<f64>>> = symbols.iter().map(|_| None).collect();
+        let mut stale_symbols = Vec::new();
+        let mut stale_indices = Vec::new();
+
+        for (i, symbol) in symbols.iter().enumerate() {
+            let mode = self.modes.get(symbol).copied().unwrap_or(ModePlan::Transparent);
+            let cached = cache.get(symbol);
+            let fresh_enough = match (mode, cached) {
+                (ModePlan::Cached, Some(_)) => true,
+                (ModePlan::Slow(interval), Some((_, fetched_at))) => {
+                    fetched_at.elapsed() < interval
+                }
+                _ => false,
+            };
+
+            if fresh_enough {
+                results[i] = Some(Ok(cached.unwrap().0));
+            } else {
+                stale_symbols.push(symbol.clone());
+                stale_indices.push(i);
+            }
+        }
+
+        if !stale_symbols.is_empty() {
+            let fetched = self.inner.get_prices(&stale_symbols);
+            let now = Instant::now();
+            for (idx, result) in stale_indices.into_iter().zip(fetched) {
+                results[idx] = Some(match result {
+                    Ok(price) => {
+                        cache.insert(symbols[idx].clone(), (price, now));
+                        Ok(price)
+                    }
+                    // Serve a stale cached value rather than going dark for this
+                    // symbol; a sibling symbol's fetch failing in the same batch
+                    // must not take down results we already have.
+                    Err(err) => match cache.get(&symbols[idx]) {
+                        Some((price, _)) => Ok(*price),
+                        None => Err(err),
+                    },
+                });
+            }
+        }
+
+        results.into_iter().map(|r| r.expect("every index filled above")).collect()
+    }
+
+    // Kline history and 24h stats aren't covered by the refresh-mode cache above;
+    // forward them directly.
+    fn get_klines(&self, symbol: &str, interval: &str, limit: u16) -> Result<Vec<f64>> {
+        self.inner.get_klines(symbol, interval, limit)
+    }
+
+    fn get_24h_changes(&self, symbols: &[String]) -> Vec<Result<f64>> {
+        self.inner.get_24h_changes(symbols)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    struct FakeApi {
+        calls: Arc<AtomicUsize>,
+        fail_symbol: Option<String>,
+    }
+
+    impl CoinPriceApiT for FakeApi {
+        fn get_prices(&self, symbols: &[String]) -> Vec<Result<f64>> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            symbols
+                .iter()
+                .map(|symbol| {
+                    if self.fail_symbol.as_deref() == Some(symbol.as_str()) {
+                        Err(color_eyre::eyre::eyre!("boom"))
+                    } else {
+                        Ok(1.0)
+                    }
+                })
+                .collect()
+        }
+
+        fn get_klines(&self, _symbol: &str, _interval: &str, _limit: u16) -> Result<Vec<f64>> {
+            Ok(Vec::new())
+        }
+
+        fn get_24h_changes(&self, symbols: &[String]) -> Vec<Result<f64>> {
+            symbols.iter().map(|_| Ok(0.0)).collect()
+        }
+    }
+
+    #[test]
+    fn transparent_always_forwards() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let fake = FakeApi { calls: calls.clone(), fail_symbol: None };
+        let cache = CacheApi::new(Box::new(fake), HashMap::new());
+        let symbols = vec!["BTCUSDT".to_string()];
+
+        cache.get_prices(&symbols);
+        cache.get_prices(&symbols);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn cached_mode_only_fetches_once() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let fake = FakeApi { calls: calls.clone(), fail_symbol: None };
+        let mut modes = HashMap::new();
+        modes.insert("BTCUSDT".to_string(), ModePlan::Cached);
+        let cache = CacheApi::new(Box::new(fake), modes);
+        let symbols = vec!["BTCUSDT".to_string()];
+
+        cache.get_prices(&symbols);
+        cache.get_prices(&symbols);
+        cache.get_prices(&symbols);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn slow_mode_throttles_within_interval() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let fake = FakeApi { calls: calls.clone(), fail_symbol: None };
+        let mut modes = HashMap::new();
+        modes.insert("BTCUSDT".to_string(), ModePlan::Slow(Duration::from_secs(60)));
+        let cache = CacheApi::new(Box::new(fake), modes);
+        let symbols = vec!["BTCUSDT".to_string()];
+
+        cache.get_prices(&symbols);
+        cache.get_prices(&symbols);
+
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn falls_back_to_stale_cache_on_fetch_error() {
+        let fake = FakeApi {
+            calls: Arc::new(AtomicUsize::new(0)),
+            fail_symbol: Some("BTCUSDT".to_string()),
+        };
+        let cache = CacheApi::new(Box::new(fake), HashMap::new());
+        cache
+            .cache
+            .lock()
+            .unwrap()
+            .insert("BTCUSDT".to_string(), (42.0, Instant::now()));
+
+        let symbols = vec!["BTCUSDT".to_string()];
+        let result = cache.get_prices(&symbols);
+
+        assert_eq!(*result[0].as_ref().unwrap(), 42.0);
+    }
+
+    #[test]
+    fn one_failing_symbol_does_not_take_down_the_rest_of_the_batch() {
+        let fake = FakeApi {
+            calls: Arc::new(AtomicUsize::new(0)),
+            fail_symbol: Some("BADUSDT".to_string()),
+        };
+        let mut modes = HashMap::new();
+        modes.insert("BTCUSDT".to_string(), ModePlan::Cached);
+        let cache = CacheApi::new(Box::new(fake), modes);
+        cache
+            .cache
+            .lock()
+            .unwrap()
+            .insert("BTCUSDT".to_string(), (100.0, Instant::now()));
+
+        let symbols = vec!["BTCUSDT".to_string(), "BADUSDT".to_string()];
+        let result = cache.get_prices(&symbols);
+
+        assert_eq!(*result[0].as_ref().unwrap(), 100.0);
+        assert!(result[1].is_err());
+    }
+}
@@ -0,0 +1,86 @@
+/// Which look-back window the per-coin sparklines are showing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimePeriod {
+    Hour,
+    Day,
+    Week,
+}
+
+impl TimePeriod {
+    pub fn next(self) -> Self {
+        match self {
+            TimePeriod::Hour => TimePeriod::Day,
+            TimePeriod::Day => TimePeriod::Week,
+            TimePeriod::Week => TimePeriod::Hour,
+        }
+    }
+
+    pub fn prev(self) -> Self {
+        match self {
+            TimePeriod::Hour => TimePeriod::Week,
+            TimePeriod::Day => TimePeriod::Hour,
+            TimePeriod::Week => TimePeriod::Day,
+        }
+    }
+
+    /// Binance kline interval for this period's candle granularity.
+    pub fn kline_interval(self) -> &'static str {
+        match self {
+            TimePeriod::Hour => "1m",
+            TimePeriod::Day => "1h",
+            TimePeriod::Week => "4h",
+        }
+    }
+
+    /// Number of candles that make up the look-back window, e.g. `Day` -> 24 x 1h candles.
+    pub fn lookback(self) -> u16 {
+        match self {
+            TimePeriod::Hour => 60,
+            TimePeriod::Day => 24,
+            TimePeriod::Week => 42,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            TimePeriod::Hour => "1H",
+            TimePeriod::Day => "1D",
+            TimePeriod::Week => "1W",
+        }
+    }
+}
+
+/// Normalizes a price history to the 0..=100 range based on its own min/max, so each
+/// coin's sparkline autoscales independently instead of sharing one global range.
+pub fn sparkline_data(history: &[f64]) -> Vec<u64> {
+    if history.is_empty() {
+        return Vec::new();
+    }
+    let min = history.iter().cloned().fold(f64::INFINITY, f64::min);
+    let max = history.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let range = (max - min).max(f64::EPSILON);
+    history
+        .iter()
+        .map(|v| (((v - min) / range) * 100.0).round() as u64)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn empty_history_yields_empty_data() {
+        assert!(sparkline_data(&[]).is_empty());
+    }
+
+    #[test]
+    fn normalizes_to_0_100_range_independent_of_absolute_price() {
+        assert_eq!(sparkline_data(&[10.0, 20.0, 15.0]), vec![0, 100, 50]);
+    }
+
+    #[test]
+    fn flat_history_does_not_divide_by_zero() {
+        assert_eq!(sparkline_data(&[5.0, 5.0, 5.0]), vec![0, 0, 0]);
+    }
+}
@@ -0,0 +1,103 @@
+use ratatui::style::{Color, Modifier, Style};
+
+/// The set of built-in themes the user can cycle through at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThemeKind {
+    Dark,
+    Light,
+    HighContrast,
+}
+
+impl ThemeKind {
+    fn next(self) -> Self {
+        match self {
+            ThemeKind::Dark => ThemeKind::Light,
+            ThemeKind::Light => ThemeKind::HighContrast,
+            ThemeKind::HighContrast => ThemeKind::Dark,
+        }
+    }
+}
+
+/// The theme-dependent color/style choices `render` needs. Status colors (loading,
+/// error, and the green/red 24h change) are fixed in `render` and do not vary by theme.
+#[derive(Debug, Clone, Copy)]
+pub struct Theme {
+    pub title_color: Color,
+    pub border_style: Style,
+    pub background: Color,
+    /// Per-coin accent colors, cycled through by [`CoinConfig::accent`](crate::app).
+    accents: [Color; 6],
+}
+
+impl Theme {
+    pub fn from_kind(kind: ThemeKind) -> Self {
+        match kind {
+            ThemeKind::Dark => Self {
+                title_color: Color::Blue,
+                border_style: Style::default().fg(Color::White),
+                background: Color::Black,
+                accents: [
+                    Color::Green,
+                    Color::Blue,
+                    Color::Yellow,
+                    Color::Cyan,
+                    Color::Magenta,
+                    Color::LightYellow,
+                ],
+            },
+            ThemeKind::Light => Self {
+                title_color: Color::Black,
+                border_style: Style::default().fg(Color::DarkGray),
+                background: Color::White,
+                accents: [
+                    Color::Rgb(0, 100, 0),
+                    Color::Rgb(0, 0, 150),
+                    Color::Rgb(150, 100, 0),
+                    Color::Rgb(0, 100, 100),
+                    Color::Rgb(100, 0, 100),
+                    Color::Rgb(150, 75, 0),
+                ],
+            },
+            ThemeKind::HighContrast => Self {
+                title_color: Color::Yellow,
+                border_style: Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                background: Color::Black,
+                accents: [
+                    Color::White,
+                    Color::Yellow,
+                    Color::Cyan,
+                    Color::Magenta,
+                    Color::Green,
+                    Color::Red,
+                ],
+            },
+        }
+    }
+
+    pub fn accent(&self, index: usize) -> Color {
+        self.accents[index % self.accents.len()]
+    }
+}
+
+/// Shared, themeable resources threaded through `render`.
+#[derive(Debug, Clone, Copy)]
+pub struct Resources {
+    pub theme: Theme,
+    kind: ThemeKind,
+}
+
+impl Resources {
+    pub fn new() -> Self {
+        let kind = ThemeKind::Dark;
+        Self {
+            theme: Theme::from_kind(kind),
+            kind,
+        }
+    }
+
+    /// Cycles Dark -> Light -> HighContrast -> Dark.
+    pub fn cycle_theme(&mut self) {
+        self.kind = self.kind.next();
+        self.theme = Theme::from_kind(self.kind);
+    }
+}